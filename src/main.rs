@@ -1,16 +1,22 @@
 use crossterm::{
     cursor::{Hide, MoveTo},
+    event::{self, Event},
     terminal::{Clear, ClearType},
     QueueableCommand,
 };
+use std::fs::File;
 use std::io::{self, stdout, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use windows::{
     core::*,
     Win32::{
         Foundation::*,
         Media::*,
         Security::*,
-        System::{LibraryLoader::*, Performance::*, Threading::*},
+        System::{
+            Console::*, Diagnostics::ToolHelp::*, LibraryLoader::*, Performance::*,
+            SystemInformation::*, Threading::*,
+        },
     },
 };
 
@@ -20,9 +26,24 @@ type NtQueryTimerResolution = unsafe extern "system" fn(
     CurrentResolution: *mut u32,
 ) -> i32;
 
+type NtSetTimerResolution = unsafe extern "system" fn(
+    RequestedResolution: u32,
+    Set: BOOLEAN,
+    ActualResolution: *mut u32,
+) -> i32;
+
+type RtlGetVersion = unsafe extern "system" fn(*mut OSVERSIONINFOW) -> i32;
+
 struct CleanupHandler;
 struct MutexHandle(HANDLE);
 
+struct ProcessTuning {
+    pid: u32,
+    name: String,
+    original_priority: u32,
+    original_affinity: usize,
+}
+
 impl Drop for CleanupHandler {
     fn drop(&mut self) {}
 }
@@ -139,6 +160,298 @@ fn set_custom() -> bool {
     false
 }
 
+fn set_custom_resolution() -> bool {
+    print!("Enter desired resolution in ms (e.g. 0.5): ");
+    let _ = stdout().flush();
+
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        println!("Failed to read input");
+        return false;
+    }
+
+    let requested_ms: f64 = match input.trim().parse() {
+        Ok(value) if value > 0.0 => value,
+        _ => {
+            println!("Invalid resolution.");
+            return false;
+        }
+    };
+
+    // NtSetTimerResolution works in 100-ns units, so 0.5ms == 5000.
+    let requested = (requested_ms * 10_000.0).round() as u32;
+
+    unsafe {
+        let h_ntdll = match LoadLibraryW(w!("NtDll.dll")) {
+            Ok(handle) => handle,
+            Err(_) => {
+                println!("LoadLibrary failed");
+                return false;
+            }
+        };
+
+        let nt_query_timer_resolution = match GetProcAddress(h_ntdll, s!("NtQueryTimerResolution")) {
+            Some(addr) => std::mem::transmute::<_, NtQueryTimerResolution>(addr),
+            None => {
+                println!("Failed to load NtQueryTimerResolution");
+                let _ = FreeLibrary(h_ntdll);
+                return false;
+            }
+        };
+
+        let nt_set_timer_resolution = match GetProcAddress(h_ntdll, s!("NtSetTimerResolution")) {
+            Some(addr) => std::mem::transmute::<_, NtSetTimerResolution>(addr),
+            None => {
+                println!("Failed to load NtSetTimerResolution");
+                let _ = FreeLibrary(h_ntdll);
+                return false;
+            }
+        };
+
+        let mut min_res: u32 = 0;
+        let mut max_res: u32 = 0;
+        let mut cur_res: u32 = 0;
+        if nt_query_timer_resolution(&mut min_res, &mut max_res, &mut cur_res) != 0 {
+            println!("NtQueryTimerResolution failed");
+            let _ = FreeLibrary(h_ntdll);
+            return false;
+        }
+
+        // MaximumResolution is the *smallest* achievable interval and
+        // MinimumResolution the coarsest, so the valid range is
+        // [MaximumResolution, MinimumResolution].
+        let target = requested.clamp(max_res, min_res);
+        if target != requested {
+            println!(
+                "Requested {:.3}ms is out of range, clamping to {:.3}ms",
+                requested as f64 / 10000.0,
+                target as f64 / 10000.0
+            );
+        }
+
+        let mut actual: u32 = 0;
+        if nt_set_timer_resolution(target, BOOLEAN(1), &mut actual) != 0 {
+            println!("NtSetTimerResolution failed");
+            let _ = FreeLibrary(h_ntdll);
+            return false;
+        }
+
+        println!(
+            "Requested {:.3}ms, kernel granted {:.3}ms",
+            target as f64 / 10000.0,
+            actual as f64 / 10000.0
+        );
+
+        let _ = FreeLibrary(h_ntdll);
+        true
+    }
+}
+
+fn release_resolution() {
+    unsafe {
+        let h_ntdll = match LoadLibraryW(w!("NtDll.dll")) {
+            Ok(handle) => handle,
+            Err(_) => return,
+        };
+
+        let nt_query_timer_resolution = match GetProcAddress(h_ntdll, s!("NtQueryTimerResolution")) {
+            Some(addr) => std::mem::transmute::<_, NtQueryTimerResolution>(addr),
+            None => {
+                let _ = FreeLibrary(h_ntdll);
+                return;
+            }
+        };
+
+        let nt_set_timer_resolution = match GetProcAddress(h_ntdll, s!("NtSetTimerResolution")) {
+            Some(addr) => std::mem::transmute::<_, NtSetTimerResolution>(addr),
+            None => {
+                let _ = FreeLibrary(h_ntdll);
+                return;
+            }
+        };
+
+        let mut min_res: u32 = 0;
+        let mut max_res: u32 = 0;
+        let mut cur_res: u32 = 0;
+        if nt_query_timer_resolution(&mut min_res, &mut max_res, &mut cur_res) == 0 {
+            // Releasing our request lets the kernel restore the previous value;
+            // the setting only persists while the process holds it.
+            let mut actual: u32 = 0;
+            nt_set_timer_resolution(cur_res, BOOLEAN(0), &mut actual);
+        }
+
+        let _ = FreeLibrary(h_ntdll);
+    }
+}
+
+fn enumerate_processes() -> Vec<(u32, String)> {
+    let mut processes = Vec::new();
+    unsafe {
+        let snapshot = match CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) {
+            Ok(handle) => handle,
+            Err(_) => {
+                println!("Failed to create process snapshot");
+                return processes;
+            }
+        };
+
+        let mut entry = PROCESSENTRY32W {
+            dwSize: size_of::<PROCESSENTRY32W>() as u32,
+            ..Default::default()
+        };
+
+        if Process32FirstW(snapshot, &mut entry).is_ok() {
+            loop {
+                let end = entry
+                    .szExeFile
+                    .iter()
+                    .position(|&c| c == 0)
+                    .unwrap_or(entry.szExeFile.len());
+                let name = String::from_utf16_lossy(&entry.szExeFile[..end]);
+                processes.push((entry.th32ProcessID, name));
+
+                if Process32NextW(snapshot, &mut entry).is_err() {
+                    break;
+                }
+            }
+        }
+
+        let _ = CloseHandle(snapshot);
+    }
+    processes
+}
+
+fn tune_process() -> Option<ProcessTuning> {
+    let processes = enumerate_processes();
+    if processes.is_empty() {
+        println!("No processes found.");
+        return None;
+    }
+
+    for (pid, name) in &processes {
+        println!("{:>6}  {}", pid, name);
+    }
+
+    print!("Enter the PID of the process to tune: ");
+    let _ = stdout().flush();
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        println!("Failed to read input");
+        return None;
+    }
+
+    let pid: u32 = match input.trim().parse() {
+        Ok(value) => value,
+        Err(_) => {
+            println!("Invalid PID.");
+            return None;
+        }
+    };
+
+    let name = processes
+        .iter()
+        .find(|(p, _)| *p == pid)
+        .map(|(_, n)| n.clone())
+        .unwrap_or_default();
+
+    unsafe {
+        let handle = match OpenProcess(
+            PROCESS_SET_INFORMATION | PROCESS_QUERY_INFORMATION,
+            false,
+            pid,
+        ) {
+            Ok(handle) => handle,
+            Err(_) => {
+                println!("Failed to open process {} (are you elevated?)", pid);
+                return None;
+            }
+        };
+
+        // Remember the original knobs so they can be reverted later.
+        let original_priority = GetPriorityClass(handle);
+        if original_priority == 0 {
+            println!("Failed to read priority class");
+            let _ = CloseHandle(handle);
+            return None;
+        }
+
+        let mut original_affinity: usize = 0;
+        let mut system_affinity: usize = 0;
+        if GetProcessAffinityMask(handle, &mut original_affinity, &mut system_affinity).is_err() {
+            println!("Failed to read affinity mask");
+            let _ = CloseHandle(handle);
+            return None;
+        }
+
+        if SetPriorityClass(handle, HIGH_PRIORITY_CLASS).is_err() {
+            println!("Failed to raise priority class");
+            let _ = CloseHandle(handle);
+            return None;
+        }
+        println!("Raised {} (PID {}) to HIGH_PRIORITY_CLASS", name, pid);
+
+        print!(
+            "Enter CPU affinity mask in hex (system mask {:#x}, blank to keep): ",
+            system_affinity
+        );
+        let _ = stdout().flush();
+        let mut mask_input = String::new();
+        if io::stdin().read_line(&mut mask_input).is_ok() {
+            let trimmed = mask_input.trim();
+            if !trimmed.is_empty() {
+                match usize::from_str_radix(trimmed.trim_start_matches("0x"), 16) {
+                    Ok(mask) if mask != 0 && mask & system_affinity == mask => {
+                        if SetProcessAffinityMask(handle, mask).is_ok() {
+                            println!("Confined {} to affinity mask {:#x}", name, mask);
+                        } else {
+                            println!("Failed to set affinity mask");
+                        }
+                    }
+                    _ => println!("Invalid mask, leaving affinity unchanged."),
+                }
+            }
+        }
+
+        let _ = CloseHandle(handle);
+
+        Some(ProcessTuning {
+            pid,
+            name,
+            original_priority,
+            original_affinity,
+        })
+    }
+}
+
+fn restore_process(tuning: &ProcessTuning) -> bool {
+    unsafe {
+        let handle = match OpenProcess(PROCESS_SET_INFORMATION, false, tuning.pid) {
+            Ok(handle) => handle,
+            Err(_) => {
+                println!("Failed to open process {} for restore", tuning.pid);
+                return false;
+            }
+        };
+
+        let priority_ok =
+            SetPriorityClass(handle, PROCESS_CREATION_FLAGS(tuning.original_priority)).is_ok();
+        let affinity_ok = SetProcessAffinityMask(handle, tuning.original_affinity).is_ok();
+
+        let _ = CloseHandle(handle);
+
+        if priority_ok && affinity_ok {
+            println!(
+                "Restored {} (PID {}) to its original priority/affinity",
+                tuning.name, tuning.pid
+            );
+            true
+        } else {
+            println!("Failed to fully restore process {}", tuning.pid);
+            false
+        }
+    }
+}
+
 fn measure(iterations: u32) {
     unsafe {
         let h_ntdll = match LoadLibraryW(w!("NtDll.dll")) {
@@ -166,7 +479,8 @@ fn measure(iterations: u32) {
             return;
         }
 
-        let mut total_elapsed = 0.0;
+        // Keep every sample so we can report the tail, not just the mean.
+        let mut samples: Vec<f64> = Vec::with_capacity(iterations as usize);
 
         for _ in 0..iterations {
             let mut min_res: u32 = 0;
@@ -187,25 +501,322 @@ fn measure(iterations: u32) {
             let _ = QueryPerformanceCounter(&mut end);
 
             let elapsed = (end - start) as f64 / freq as f64 * 1000.0;
-            total_elapsed += elapsed;
+            samples.push(elapsed);
         }
 
-        let avg_elapsed = total_elapsed / iterations as f64;
         let mut cur_res: u32 = 0;
         let mut min_res: u32 = 0;
         let mut max_res: u32 = 0;
         nt_query_timer_resolution(&mut min_res, &mut max_res, &mut cur_res);
 
         println!(
-            "Average over {} iterations: {:.3}ms (Resolution: {:.3}ms, Min: {:.3}ms, Max: {:.3}ms)",
-            iterations,
-            avg_elapsed,
+            "Resolution: {:.3}ms (Min: {:.3}ms, Max: {:.3}ms)",
             cur_res as f64 / 10000.0,
             min_res as f64 / 10000.0,
             max_res as f64 / 10000.0
         );
+        println!("Timer scoping: {}", scoping_mode_label());
+        report_distribution(&samples);
+
+        print!("Export samples to CSV? (y/N): ");
+        let _ = stdout().flush();
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer).is_ok()
+            && matches!(answer.trim(), "y" | "Y")
+        {
+            export_csv(&samples);
+        }
+
+        let _ = FreeLibrary(h_ntdll);
+    }
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0 * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+fn report_distribution(samples: &[f64]) {
+    if samples.is_empty() {
+        println!("No samples collected.");
+        return;
+    }
+
+    let n = samples.len();
+    let mean = samples.iter().sum::<f64>() / n as f64;
+    let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+    let stddev = variance.sqrt();
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    println!("Samples : {}", n);
+    println!("Min     : {:.3}ms", sorted[0]);
+    println!("Max     : {:.3}ms", sorted[n - 1]);
+    println!("Mean    : {:.3}ms", mean);
+    println!("Stddev  : {:.3}ms", stddev);
+    println!("p50     : {:.3}ms", percentile(&sorted, 50.0));
+    println!("p95     : {:.3}ms", percentile(&sorted, 95.0));
+    println!("p99     : {:.3}ms", percentile(&sorted, 99.0));
+    println!("p99.9   : {:.3}ms", percentile(&sorted, 99.9));
+
+    print_histogram(&sorted);
+}
+
+fn print_histogram(sorted: &[f64]) {
+    const BUCKETS: usize = 10;
+    const WIDTH: usize = 40;
+
+    let min = sorted[0];
+    let max = sorted[sorted.len() - 1];
+    let span = max - min;
+    if span <= 0.0 {
+        println!("(all samples at {:.3}ms)", min);
+        return;
+    }
+
+    let mut counts = [0usize; BUCKETS];
+    for &sample in sorted {
+        let mut bucket = ((sample - min) / span * BUCKETS as f64) as usize;
+        if bucket >= BUCKETS {
+            bucket = BUCKETS - 1;
+        }
+        counts[bucket] += 1;
+    }
+
+    let peak = counts.iter().copied().max().unwrap_or(1).max(1);
+    println!("Distribution:");
+    for (i, &count) in counts.iter().enumerate() {
+        let low = min + span * i as f64 / BUCKETS as f64;
+        let high = min + span * (i + 1) as f64 / BUCKETS as f64;
+        let bar = "#".repeat(count * WIDTH / peak);
+        println!("{:6.3}-{:6.3}ms | {:<width$} {}", low, high, bar, count, width = WIDTH);
+    }
+}
+
+fn export_csv(samples: &[f64]) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let filename = format!("timer_samples_{}.csv", timestamp);
+
+    match File::create(&filename) {
+        Ok(mut file) => {
+            let _ = writeln!(file, "iteration,elapsed_ms");
+            for (i, sample) in samples.iter().enumerate() {
+                let _ = writeln!(file, "{},{:.6}", i, sample);
+            }
+            println!("Wrote {} samples to {}", samples.len(), filename);
+        }
+        Err(e) => println!("Failed to write CSV: {}", e),
+    }
+}
+
+fn find_process_by_name(target: &str) -> Option<u32> {
+    enumerate_processes()
+        .into_iter()
+        .find(|(_, name)| name.to_lowercase() == target)
+        .map(|(pid, _)| pid)
+}
+
+fn apply_priority(pid: u32, name: &str) -> Option<ProcessTuning> {
+    unsafe {
+        let handle = match OpenProcess(
+            PROCESS_SET_INFORMATION | PROCESS_QUERY_INFORMATION,
+            false,
+            pid,
+        ) {
+            Ok(handle) => handle,
+            Err(_) => {
+                println!("Failed to open process {} (are you elevated?)", pid);
+                return None;
+            }
+        };
+
+        let original_priority = GetPriorityClass(handle);
+        let mut original_affinity: usize = 0;
+        let mut system_affinity: usize = 0;
+        if original_priority == 0
+            || GetProcessAffinityMask(handle, &mut original_affinity, &mut system_affinity).is_err()
+        {
+            println!("Failed to read current priority/affinity");
+            let _ = CloseHandle(handle);
+            return None;
+        }
+
+        if SetPriorityClass(handle, HIGH_PRIORITY_CLASS).is_err() {
+            println!("Failed to raise priority class");
+            let _ = CloseHandle(handle);
+            return None;
+        }
+        println!("Raised {} (PID {}) to HIGH_PRIORITY_CLASS", name, pid);
+
+        let _ = CloseHandle(handle);
+
+        Some(ProcessTuning {
+            pid,
+            name: name.to_string(),
+            original_priority,
+            original_affinity,
+        })
+    }
+}
+
+// Non-blocking check for a pending keypress; also doubles as the poll delay.
+fn key_pressed() -> bool {
+    matches!(event::poll(Duration::from_millis(250)), Ok(true))
+        && matches!(event::read(), Ok(Event::Key(_)))
+}
+
+fn watch_process() {
+    print!("Enter target executable name (e.g. game.exe): ");
+    let _ = stdout().flush();
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        println!("Failed to read input");
+        return;
+    }
+    let target = input.trim().to_lowercase();
+    if target.is_empty() {
+        println!("No target given.");
+        return;
+    }
+
+    print!("Also raise priority while it runs? (y/N): ");
+    let _ = stdout().flush();
+    let mut answer = String::new();
+    let tune_priority =
+        io::stdin().read_line(&mut answer).is_ok() && matches!(answer.trim(), "y" | "Y");
+
+    println!("Watching for {} ... press any key to stop.", target);
+
+    // Phase 1: wait for the process to appear, cancelable between polls.
+    let pid = loop {
+        if let Some(pid) = find_process_by_name(&target) {
+            break pid;
+        }
+        if key_pressed() {
+            println!("Stopped watching.");
+            return;
+        }
+    };
+
+    println!("{} appeared (PID {}); applying tuning.", target, pid);
+    set_custom();
+    let tuning = if tune_priority {
+        apply_priority(pid, &target)
+    } else {
+        None
+    };
+
+    // Phase 2: wait for the process to exit using a cancelable timed wait.
+    unsafe {
+        match OpenProcess(SYNCHRONIZE, false, pid) {
+            Ok(handle) => {
+                loop {
+                    if WaitForSingleObject(handle, 250) == WAIT_OBJECT_0 {
+                        println!("{} exited; reverting.", target);
+                        break;
+                    }
+                    if key_pressed() {
+                        println!("Stopped watching (process still running); reverting.");
+                        break;
+                    }
+                }
+                let _ = CloseHandle(handle);
+            }
+            Err(_) => {
+                println!("Failed to open process for waiting; reverting now.");
+            }
+        }
+    }
+
+    if let Some(tuning) = tuning {
+        restore_process(&tuning);
+    }
+    release_resolution();
+    reset_to_default();
+}
+
+// Ensure the kernel timer state is released even on an abrupt Ctrl-C exit.
+unsafe extern "system" fn ctrl_handler(_ctrl_type: u32) -> BOOL {
+    release_resolution();
+    let _ = timeEndPeriod(1);
+    // Returning FALSE lets the default handler terminate the process.
+    BOOL(0)
+}
+
+fn os_build() -> Option<u32> {
+    unsafe {
+        let h_ntdll = LoadLibraryW(w!("NtDll.dll")).ok()?;
+        let rtl_get_version = match GetProcAddress(h_ntdll, s!("RtlGetVersion")) {
+            Some(addr) => std::mem::transmute::<_, RtlGetVersion>(addr),
+            None => {
+                let _ = FreeLibrary(h_ntdll);
+                return None;
+            }
+        };
 
+        let mut info = OSVERSIONINFOW {
+            dwOSVersionInfoSize: size_of::<OSVERSIONINFOW>() as u32,
+            ..Default::default()
+        };
+        let status = rtl_get_version(&mut info);
         let _ = FreeLibrary(h_ntdll);
+
+        if status == 0 {
+            Some(info.dwBuildNumber)
+        } else {
+            None
+        }
+    }
+}
+
+// Build 19041 (Windows 10 2004) is where timeBeginPeriod became scoped to the
+// requesting process's foreground/background state rather than system-wide.
+fn uses_per_process_timer_scoping() -> bool {
+    os_build().map(|build| build >= 19041).unwrap_or(false)
+}
+
+fn scoping_mode_label() -> &'static str {
+    if uses_per_process_timer_scoping() {
+        "per-process (Win10 2004+/Win11)"
+    } else {
+        "global (legacy)"
+    }
+}
+
+fn opt_in_timer_resolution() -> bool {
+    if !uses_per_process_timer_scoping() {
+        println!("Legacy global timer behavior in effect; no opt-in needed.");
+        return true;
+    }
+
+    unsafe {
+        let mut state = PROCESS_POWER_THROTTLING_STATE {
+            Version: PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+            ControlMask: PROCESS_POWER_THROTTLING_IGNORE_TIMER_RESOLUTION,
+            StateMask: 0,
+        };
+
+        if SetProcessInformation(
+            GetCurrentProcess(),
+            ProcessPowerThrottling,
+            &mut state as *mut _ as *const _,
+            size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+        )
+        .is_ok()
+        {
+            println!("Opted in to always honor the requested timer resolution.");
+            true
+        } else {
+            println!("Failed to override timer-resolution scoping.");
+            false
+        }
     }
 }
 
@@ -233,13 +844,25 @@ fn main() {
         return;
     }
 
+    unsafe {
+        let _ = SetConsoleCtrlHandler(Some(ctrl_handler), true);
+    }
+
+    let mut tuning: Option<ProcessTuning> = None;
+
     loop {
         clear_console();
+        println!("Timer scoping: {}", scoping_mode_label());
         println!("1. Set to 1ms (if supported)");
         println!("2. Measure");
         println!("3. Close");
         println!("4. Reset to default (~15.6ms)");
-        print!("Select an option (1-4): ");
+        println!("5. Set custom resolution (sub-ms)");
+        println!("6. Tune a process (priority/affinity)");
+        println!("7. Restore tuned process");
+        println!("8. Watch for a process (auto-apply/revert)");
+        println!("9. Override timer scoping (opt-in, Win10 2004+/Win11)");
+        print!("Select an option (1-9): ");
         let _ = stdout().flush();
 
         let mut input = String::new();
@@ -262,16 +885,59 @@ fn main() {
             }
             "3" => {
                 println!("Closing application...");
+                if let Some(tuning) = tuning.take() {
+                    restore_process(&tuning);
+                }
+                release_resolution();
                 break;
             }
             "4" => {
+                release_resolution();
                 reset_to_default();
                 println!("Press Enter to continue...");
                 let mut _input = String::new();
                 let _ = io::stdin().read_line(&mut _input);
             }
+            "5" => {
+                set_custom_resolution();
+                println!("Press Enter to continue...");
+                let mut _input = String::new();
+                let _ = io::stdin().read_line(&mut _input);
+            }
+            "6" => {
+                if let Some(previous) = tuning.take() {
+                    restore_process(&previous);
+                }
+                tuning = tune_process();
+                println!("Press Enter to continue...");
+                let mut _input = String::new();
+                let _ = io::stdin().read_line(&mut _input);
+            }
+            "7" => {
+                match tuning.take() {
+                    Some(previous) => {
+                        restore_process(&previous);
+                    }
+                    None => println!("No tuned process to restore."),
+                }
+                println!("Press Enter to continue...");
+                let mut _input = String::new();
+                let _ = io::stdin().read_line(&mut _input);
+            }
+            "8" => {
+                watch_process();
+                println!("Press Enter to continue...");
+                let mut _input = String::new();
+                let _ = io::stdin().read_line(&mut _input);
+            }
+            "9" => {
+                opt_in_timer_resolution();
+                println!("Press Enter to continue...");
+                let mut _input = String::new();
+                let _ = io::stdin().read_line(&mut _input);
+            }
             _ => {
-                println!("Invalid option! Please select 1, 2, 3, or 4.");
+                println!("Invalid option! Please select 1, 2, 3, 4, 5, 6, 7, 8, or 9.");
                 println!("Press Enter to continue...");
                 let mut _input = String::new();
                 let _ = io::stdin().read_line(&mut _input);